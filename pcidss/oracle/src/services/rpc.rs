@@ -2,30 +2,300 @@
 use async_trait::async_trait;
 use chrono::{Months, Utc};
 use iso8583_rs::iso8583::iso_spec::IsoMsg;
-use jsonrpsee::{core::RpcResult, proc_macros::rpc, server::Server};
-use jsonrpsee_types::error::ErrorCode;
+use jsonrpsee::{
+	core::{RpcResult, SubscriptionResult},
+	proc_macros::rpc,
+	server::Server,
+	types::ErrorObjectOwned,
+	ws_client::WsClientBuilder,
+	PendingSubscriptionSink, SubscriptionMessage,
+};
 use log::info;
 use op_core::{
 	bank_account::models::{BankAccount, BankAccountCreate},
 	error::DomainError,
-	transaction::models::Transaction,
+	transaction::models::{Transaction, TxStatus},
 };
-use std::{error::Error, net::SocketAddr, sync::Arc};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, error::Error, net::SocketAddr, sync::Arc, time::Duration};
 use subxt_signer::{sr25519, sr25519::Signature};
+use tokio::sync::{broadcast, RwLock};
 
 use super::processor::Iso8583MessageProcessor;
 use crate::types::constants::DEV_ACCOUNTS;
 
+/// Capacity of the broadcast channel used to fan settlement notifications out to subscribers.
+///
+/// Sized generously above expected settlement throughput; a subscriber that falls this far
+/// behind is considered lagged and its subscription is closed rather than buffered further.
+const TRANSACTION_BROADCAST_CAPACITY: usize = 1024;
+
+/// How much settlement certainty a caller wants before `submit_iso8583` returns
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Commitment {
+	/// Return as soon as the processor validates and applies the balance change locally
+	#[default]
+	Processed,
+	/// Return only once the OCW has observed the balance change on-chain
+	Finalized,
+}
+
+/// Configuration for `submit_iso8583` controlling how much settlement certainty to wait for
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SubmitConfig {
+	/// Settlement certainty required before the call returns
+	#[serde(default)]
+	pub commitment: Commitment,
+	/// Maximum time to wait for the requested commitment level before timing out
+	#[serde(default = "default_submit_timeout_ms")]
+	pub timeout_ms: u64,
+}
+
+impl Default for SubmitConfig {
+	fn default() -> Self {
+		Self { commitment: Commitment::default(), timeout_ms: default_submit_timeout_ms() }
+	}
+}
+
+fn default_submit_timeout_ms() -> u64 {
+	30_000
+}
+
+/// Default `limit` applied to `get_transactions` when the caller does not specify one
+const DEFAULT_GET_TRANSACTIONS_LIMIT: u32 = 50;
+
+/// Upper bound on `limit` for `get_transactions`, regardless of what the caller requests
+const MAX_GET_TRANSACTIONS_LIMIT: u32 = 200;
+
+/// Filter and pagination options for `get_transactions`
+///
+/// Mirrors Solana's `getConfirmedSignaturesForAddress2`: `before`/`until` page backwards through
+/// history by transaction id, and `limit` bounds the response size for high-volume merchant
+/// cards.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetTransactionsFilter {
+	/// Only return transactions strictly before this transaction id, exclusive
+	pub before: Option<uuid::Uuid>,
+	/// Stop once this transaction id is reached, exclusive
+	pub until: Option<uuid::Uuid>,
+	/// Maximum number of transactions to return; capped at `MAX_GET_TRANSACTIONS_LIMIT`
+	pub limit: Option<u32>,
+	/// Only return transactions in this status
+	pub status: Option<TxStatus>,
+}
+
+impl GetTransactionsFilter {
+	/// `limit`, defaulted and capped to the server-enforced bounds
+	fn effective_limit(&self) -> u32 {
+		self.limit.unwrap_or(DEFAULT_GET_TRANSACTIONS_LIMIT).min(MAX_GET_TRANSACTIONS_LIMIT)
+	}
+}
+
+/// Orders `transactions` most-recent-first, applies `filter`'s `before`/`until`/`status`, and
+/// caps the result at `filter.effective_limit()`.
+///
+/// This is what a real `TransactionRepository` pushes down into its indexed query so a
+/// high-volume merchant card's full history is never pulled into memory; [`InMemoryStore`] has
+/// no query planner to push down into, so it applies this directly over everything it holds for
+/// the account instead.
+fn apply_transactions_filter(
+	mut transactions: Vec<Transaction>,
+	filter: &GetTransactionsFilter,
+) -> Vec<Transaction> {
+	transactions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+	if let Some(before) = filter.before {
+		if let Some(pos) = transactions.iter().position(|tx| tx.id == before) {
+			transactions = transactions.split_off(pos + 1);
+		}
+	}
+
+	if let Some(until) = filter.until {
+		if let Some(pos) = transactions.iter().position(|tx| tx.id == until) {
+			transactions.truncate(pos);
+		}
+	}
+
+	if let Some(status) = filter.status {
+		transactions.retain(|tx| tx.status == status);
+	}
+
+	transactions.truncate(filter.effective_limit() as usize);
+
+	transactions
+}
+
+/// Structured, machine-readable errors returned by the Oracle RPC API
+///
+/// Codes live in the `-32000..-32099` custom server-error range reserved by the JSON-RPC spec,
+/// mirroring Solana's `RpcCustomError` so callers can branch on `code` instead of matching on
+/// the human-readable message. The `data` field (attached via `Serialize`) carries whatever
+/// machine-readable context is available: a hash of the card number (never the PAN itself, to
+/// stay PCI DSS compliant) or the failing ISO field.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum OracleError {
+	/// No bank account is registered for the given card number or on-chain account id
+	CardNotFound { card_number_hash: String },
+	/// The card exists but has expired
+	CardExpired { card_number_hash: String },
+	/// The requested transaction would leave the account balance negative
+	InsufficientFunds { card_number_hash: String },
+	/// The ISO8583 message is malformed or missing a required field
+	MalformedIsoMessage { field: String },
+	/// A settlement completed locally but the requested commitment level was not reached in time
+	FinalityTimeout { timeout_ms: u64 },
+	/// The supplied ed25519 signature was malformed or did not verify
+	InvalidSignature,
+	/// A request was rejected for a reason not covered by a more specific variant
+	InvalidRequest { message: String },
+	/// Catch-all for unexpected internal failures
+	Internal { message: String },
+}
+
+impl OracleError {
+	/// Stable numeric code in the `-32000..-32099` custom server-error range
+	fn code(&self) -> i32 {
+		match self {
+			OracleError::CardNotFound { .. } => -32000,
+			OracleError::CardExpired { .. } => -32001,
+			OracleError::InsufficientFunds { .. } => -32002,
+			OracleError::MalformedIsoMessage { .. } => -32003,
+			OracleError::FinalityTimeout { .. } => -32004,
+			OracleError::InvalidSignature => -32005,
+			OracleError::InvalidRequest { .. } => -32006,
+			OracleError::Internal { .. } => -32010,
+		}
+	}
+
+	fn message(&self) -> &'static str {
+		match self {
+			OracleError::CardNotFound { .. } => "card not found",
+			OracleError::CardExpired { .. } => "card has expired",
+			OracleError::InsufficientFunds { .. } => "insufficient funds",
+			OracleError::MalformedIsoMessage { .. } => "malformed ISO8583 message",
+			OracleError::FinalityTimeout { .. } => "timed out waiting for settlement finality",
+			OracleError::InvalidSignature => "invalid signature",
+			OracleError::InvalidRequest { .. } => "invalid request",
+			OracleError::Internal { .. } => "internal error",
+		}
+	}
+}
+
+impl From<OracleError> for ErrorObjectOwned {
+	fn from(err: OracleError) -> Self {
+		let code = err.code();
+		let message = err.message();
+		let data = serde_json::to_value(&err).ok();
+		ErrorObjectOwned::owned(code, message, data)
+	}
+}
+
+/// What a `DomainError::BadRequest` message actually represents, inferred by keyword until
+/// `op_core` grows dedicated variants for these cases.
+enum BadRequestKind {
+	CardExpired,
+	InsufficientFunds,
+	Other,
+}
+
+/// Classifies a `DomainError::BadRequest` message by keyword, shared by `oracle_error_for_card`
+/// and `oracle_error_for_iso_message` so both error paths agree on what counts as "expired" or
+/// "insufficient funds".
+fn classify_bad_request(message: &str) -> BadRequestKind {
+	let message = message.to_lowercase();
+	if message.contains("expired") {
+		BadRequestKind::CardExpired
+	} else if message.contains("insufficient") {
+		BadRequestKind::InsufficientFunds
+	} else {
+		BadRequestKind::Other
+	}
+}
+
+/// Maps a `DomainError` encountered while looking up or acting on `card_number`'s account onto
+/// an `OracleError`, hashing the card number so the PAN never leaves this process in an error.
+fn oracle_error_for_card(err: DomainError, card_number: &str) -> OracleError {
+	let card_number_hash = hash_card_number(card_number);
+	match err {
+		DomainError::NotFound(_) => OracleError::CardNotFound { card_number_hash },
+		DomainError::BadRequest(message) => match classify_bad_request(&message) {
+			BadRequestKind::CardExpired => OracleError::CardExpired { card_number_hash },
+			BadRequestKind::InsufficientFunds => OracleError::InsufficientFunds { card_number_hash },
+			BadRequestKind::Other => OracleError::InvalidRequest { message },
+		},
+		DomainError::ApiError(message) | DomainError::InternalServerError(message) =>
+			OracleError::Internal { message },
+	}
+}
+
+/// Maps a `DomainError` from submitting `iso_msg` onto an `OracleError`.
+///
+/// The card number is not parsed out at this layer (that happens inside the ISO8583 processor
+/// itself), so a declined-for-expired or declined-for-insufficient-funds `BadRequest` is hashed
+/// from the raw submitted message rather than the PAN; `oracle_error_for_card` is used instead
+/// wherever the card number is already in hand.
+fn oracle_error_for_iso_message(err: DomainError, iso_msg: &[u8]) -> OracleError {
+	match err {
+		DomainError::BadRequest(field) => match classify_bad_request(&field) {
+			BadRequestKind::CardExpired =>
+				OracleError::CardExpired { card_number_hash: hash_card_number(&String::from_utf8_lossy(iso_msg)) },
+			BadRequestKind::InsufficientFunds => OracleError::InsufficientFunds {
+				card_number_hash: hash_card_number(&String::from_utf8_lossy(iso_msg)),
+			},
+			BadRequestKind::Other => OracleError::MalformedIsoMessage { field },
+		},
+		DomainError::NotFound(message) => OracleError::InvalidRequest { message },
+		DomainError::ApiError(message) | DomainError::InternalServerError(message) =>
+			OracleError::Internal { message },
+	}
+}
+
+/// Hashes a card number with SHA-256 so it never appears in an error response verbatim
+fn hash_card_number(card_number: &str) -> String {
+	use sha2::{Digest, Sha256};
+	let digest = Sha256::digest(card_number.as_bytes());
+	hex::encode(digest)
+}
+
 /// PCIDSS Compliant Oracle RPC API
 #[rpc(server, client, namespace = "pcidss")]
 pub trait OracleApi<IsoMsg> {
 	/// Submit ISO8583 message for processing
+	///
+	/// `config` controls how much settlement certainty is required before the response is
+	/// returned; defaults to `Commitment::Processed` (current, low-latency behavior).
 	#[method(name = "submit_iso8583")]
-	async fn submit_iso8583(&self, iso_msg: Vec<u8>) -> RpcResult<Vec<u8>>;
+	async fn submit_iso8583(
+		&self,
+		iso_msg: Vec<u8>,
+		config: Option<SubmitConfig>,
+	) -> RpcResult<Vec<u8>>;
+
+	/// Submit a batch of ISO8583 messages for processing in one round trip
+	///
+	/// When `atomic` is set, every message's balance/nonce mutation is applied within a single
+	/// database transaction: if any message in the batch fails validation, the whole batch is
+	/// rolled back so a multi-leg settlement (e.g. reversal + re-auth) never leaves accounts
+	/// partially updated. When unset, each message is applied independently and a failure only
+	/// affects its own entry in the result vector.
+	#[method(name = "submit_iso8583_batch")]
+	async fn submit_iso8583_batch(
+		&self,
+		msgs: Vec<Vec<u8>>,
+		atomic: bool,
+	) -> RpcResult<Vec<Result<Vec<u8>, OracleError>>>;
 
 	/// Get transactions by card number
+	///
+	/// Returns the most recent transactions first, bounded and optionally filtered by
+	/// `filter`; see [`GetTransactionsFilter`] for paging through a card's full history.
 	#[method(name = "get_transactions")]
-	async fn get_transactions(&self, card_number: String) -> RpcResult<Vec<Transaction>>;
+	async fn get_transactions(
+		&self,
+		card_number: String,
+		filter: Option<GetTransactionsFilter>,
+	) -> RpcResult<Vec<Transaction>>;
 
 	/// Get bank account by card number
 	#[method(name = "get_bank_account")]
@@ -40,83 +310,251 @@ pub trait OracleApi<IsoMsg> {
 		signature: Vec<u8>,
 		account_ids: Vec<String>,
 	) -> RpcResult<Vec<(String, u32)>>;
+
+	/// Subscribe to settlement notifications for a card number or on-chain account id
+	///
+	/// A notification is pushed the moment a transaction for the matching card/account is
+	/// processed; the subscription stays open until the client unsubscribes, the connection
+	/// is dropped, or the subscriber falls more than `TRANSACTION_BROADCAST_CAPACITY`
+	/// notifications behind, in which case it is closed.
+	#[subscription(name = "subscribeTransaction" => "transaction", unsubscribe = "unsubscribeTransaction", item = Transaction)]
+	async fn subscribe_transaction(&self, card_number_or_account_id: String) -> SubscriptionResult;
+}
+
+/// Bank account lookups needed by the Oracle RPC handlers, extracted so they can be satisfied
+/// either by `Iso8583MessageProcessor` (delegating to its `bank_account_controller`) or by
+/// [`InMemoryStore`].
+#[async_trait]
+trait BankAccountRepository: Send + Sync {
+	async fn find_by_card_number(&self, card_number: &str) -> Result<Option<BankAccount>, DomainError>;
+	async fn find_by_account_id(&self, account_id: &str) -> Result<Option<BankAccount>, DomainError>;
+}
+
+/// Transaction lookups needed by the Oracle RPC handlers, extracted so they can be satisfied
+/// either by `Iso8583MessageProcessor` (delegating to its `transaction_controller`) or by
+/// [`InMemoryStore`].
+#[async_trait]
+trait TransactionRepository: Send + Sync {
+	/// Returns `bank_account_id`'s transactions already ordered, cursored and capped per
+	/// `filter`. The `Iso8583MessageProcessor` implementation passes `before`/`until`/`limit`/
+	/// `status` into the query itself rather than fetching the account's entire history;
+	/// [`InMemoryStore`] loads everything it holds for the account and filters it in memory.
+	async fn find_by_bank_account_id(
+		&self,
+		bank_account_id: &uuid::Uuid,
+		filter: &GetTransactionsFilter,
+	) -> Result<Vec<Transaction>, DomainError>;
+
+	/// Records a newly submitted `transaction`
+	async fn insert(&self, transaction: Transaction) -> Result<(), DomainError>;
+}
+
+/// Submits ISO8583 messages and applies their resulting mutations, extracted so
+/// [`OracleApiImpl`] can share a single set of RPC handlers between the Postgres-backed
+/// `Iso8583MessageProcessor` and [`InMemoryStore`]'s in-process fixture.
+#[async_trait]
+trait Iso8583Submitter: Send + Sync {
+	/// Processes `iso_msg`, returning the response bytes to hand back to the caller and the
+	/// [`Transaction`] it produced.
+	async fn submit(&self, iso_msg: &mut Vec<u8>) -> Result<(Vec<u8>, Transaction), DomainError>;
+
+	/// Processes `msgs` as a batch; see `OracleApi::submit_iso8583_batch` for `atomic`'s meaning.
+	async fn submit_batch(
+		&self,
+		msgs: Vec<Vec<u8>>,
+		atomic: bool,
+	) -> Vec<Result<(Vec<u8>, Transaction), DomainError>>;
+
+	/// Blocks until `transaction` is observed on-chain, for `Commitment::Finalized`
+	async fn wait_for_finality(&self, transaction: &Transaction);
+}
+
+#[async_trait]
+impl BankAccountRepository for Iso8583MessageProcessor {
+	async fn find_by_card_number(&self, card_number: &str) -> Result<Option<BankAccount>, DomainError> {
+		self.bank_account_controller.find_by_card_number(card_number).await
+	}
+
+	async fn find_by_account_id(&self, account_id: &str) -> Result<Option<BankAccount>, DomainError> {
+		self.bank_account_controller.find_by_account_id(account_id).await
+	}
+}
+
+#[async_trait]
+impl TransactionRepository for Iso8583MessageProcessor {
+	async fn find_by_bank_account_id(
+		&self,
+		bank_account_id: &uuid::Uuid,
+		filter: &GetTransactionsFilter,
+	) -> Result<Vec<Transaction>, DomainError> {
+		// Cursor/limit/status are passed straight into the query so a high-volume merchant
+		// card's entire history is never pulled into memory just to be filtered here.
+		self.transaction_controller
+			.find_by_bank_account_id(
+				bank_account_id,
+				filter.before,
+				filter.until,
+				filter.status,
+				filter.effective_limit(),
+			)
+			.await
+	}
+
+	async fn insert(&self, _transaction: Transaction) -> Result<(), DomainError> {
+		// `process`/`process_batch` already persist the transaction as part of settlement;
+		// nothing further to do here.
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl Iso8583Submitter for Iso8583MessageProcessor {
+	async fn submit(&self, iso_msg: &mut Vec<u8>) -> Result<(Vec<u8>, Transaction), DomainError> {
+		self.process(iso_msg).await
+	}
+
+	async fn submit_batch(
+		&self,
+		msgs: Vec<Vec<u8>>,
+		atomic: bool,
+	) -> Vec<Result<(Vec<u8>, Transaction), DomainError>> {
+		self.process_batch(msgs, atomic).await
+	}
+
+	async fn wait_for_finality(&self, transaction: &Transaction) {
+		// Delegates to the processor's own OCW-observing future; this snapshot of the tree has
+		// no `processor.rs` to confirm that inherent method's behavior against, and no live
+		// chain to test it with. The `Commitment::Finalized` timeout logic that wraps this call
+		// in `OracleApiImpl::submit_iso8583` is exercised below against `NeverFinalizingStore`, a
+		// stand-in whose `wait_for_finality` is under our control.
+		self.wait_for_finality(transaction).await
+	}
 }
 
 /// PCIDSS Compliant Oracle RPC API implementation
-pub struct OracleApiImpl {
-	/// ISO8583 message processor
-	pub processor: Arc<Iso8583MessageProcessor>,
+///
+/// Generic over `P` so the real, Postgres-backed `Iso8583MessageProcessor` and
+/// [`InMemoryStore`]'s in-process fixture share every handler below instead of maintaining two
+/// near-identical copies; see [`BankAccountRepository`], [`TransactionRepository`] and
+/// [`Iso8583Submitter`].
+pub struct OracleApiImpl<P> {
+	/// Data access and ISO8583 submission
+	pub backend: Arc<P>,
 	/// OCW signer account
 	pub signer: sr25519::PublicKey,
+	/// Broadcasts every successfully settled transaction to `subscribe_transaction` listeners
+	pub transaction_sender: broadcast::Sender<Transaction>,
 }
 
 #[async_trait]
-impl OracleApiServer<IsoMsg> for OracleApiImpl {
-	async fn submit_iso8583(&self, iso_msg: Vec<u8>) -> RpcResult<Vec<u8>> {
+impl<P> OracleApiServer<IsoMsg> for OracleApiImpl<P>
+where
+	P: BankAccountRepository + TransactionRepository + Iso8583Submitter + 'static,
+{
+	async fn submit_iso8583(
+		&self,
+		iso_msg: Vec<u8>,
+		config: Option<SubmitConfig>,
+	) -> RpcResult<Vec<u8>> {
 		log::debug!("Received ISO8583 message: {:?}", iso_msg);
 
+		let config = config.unwrap_or_default();
 		let mut iso_msg = iso_msg;
 
-		match self.processor.process(&mut iso_msg).await {
-			Ok(result) => {
-				log::info!("Processed ISO8583 message: {:?}", result.0);
-				Ok(result.0)
+		match self.backend.submit(&mut iso_msg).await {
+			Ok((response, transaction)) => {
+				log::info!("Processed ISO8583 message, transaction: {:?}", transaction.id);
+				// Best-effort: a lagging or absent subscriber must never affect the response.
+				let _ = self.transaction_sender.send(transaction.clone());
+
+				if config.commitment == Commitment::Finalized {
+					let finalized = self.backend.wait_for_finality(&transaction);
+					if tokio::time::timeout(Duration::from_millis(config.timeout_ms), finalized)
+						.await
+						.is_err()
+					{
+						log::error!(
+							"Timed out after {}ms waiting for on-chain finality of transaction {}",
+							config.timeout_ms, transaction.id
+						);
+						return Err(OracleError::FinalityTimeout { timeout_ms: config.timeout_ms }.into());
+					}
+				}
+
+				Ok(response)
 			},
 			Err(err) => {
 				log::error!("Failed to process ISO8583 message: {:?}", err.to_string());
-				let error_code = match err {
-					DomainError::ApiError(_) => ErrorCode::InternalError,
-					DomainError::InternalServerError(_) => ErrorCode::InternalError,
-					DomainError::BadRequest(_) => ErrorCode::InvalidParams,
-					DomainError::NotFound(_) => ErrorCode::InvalidParams,
-				};
-
-				Err(error_code.into())
+				Err(oracle_error_for_iso_message(err, &iso_msg).into())
 			},
 		}
 	}
 
-	async fn get_transactions(&self, card_number: String) -> RpcResult<Vec<Transaction>> {
-		log::debug!("Received get_transactions request: {:?}", card_number);
+	async fn submit_iso8583_batch(
+		&self,
+		msgs: Vec<Vec<u8>>,
+		atomic: bool,
+	) -> RpcResult<Vec<Result<Vec<u8>, OracleError>>> {
+		log::debug!("Received ISO8583 batch of {} message(s), atomic={}", msgs.len(), atomic);
+
+		// A single DB transaction covers the whole batch when `atomic`; any failing message
+		// rolls every mutation in the batch back rather than committing per message.
+		let msgs_for_errors = msgs.clone();
+		let results = self.backend.submit_batch(msgs, atomic).await;
+
+		for result in &results {
+			if let Ok((_, transaction)) = result {
+				// Best-effort: a lagging or absent subscriber must never affect the response.
+				let _ = self.transaction_sender.send(transaction.clone());
+			}
+		}
+
+		Ok(results
+			.into_iter()
+			.zip(msgs_for_errors)
+			.map(|(result, msg)| {
+				result.map(|(response, _)| response).map_err(|err| oracle_error_for_iso_message(err, &msg))
+			})
+			.collect())
+	}
+
+	async fn get_transactions(
+		&self,
+		card_number: String,
+		filter: Option<GetTransactionsFilter>,
+	) -> RpcResult<Vec<Transaction>> {
+		log::debug!("Received get_transactions request: {:?}, filter: {:?}", card_number, filter);
+
+		let filter = filter.unwrap_or_default();
 
 		let bank_account = self
-			.processor
-			.bank_account_controller
+			.backend
 			.find_by_card_number(&card_number)
 			.await
-			.map_err(|_| ErrorCode::InvalidParams)?
-			.ok_or(ErrorCode::InvalidParams)?;
+			.map_err(|err| oracle_error_for_card(err, &card_number))?
+			.ok_or_else(|| OracleError::CardNotFound { card_number_hash: hash_card_number(&card_number) })?;
 
-		self.processor
-			.transaction_controller
-			.find_by_bank_account_id(&bank_account.id)
-			.await 
-			.map_err(|err| {
-				let error_code = match err {
-					DomainError::ApiError(_) => ErrorCode::InternalError,
-					DomainError::InternalServerError(_) => ErrorCode::InternalError,
-					DomainError::BadRequest(_) => ErrorCode::InvalidParams,
-					DomainError::NotFound(_) => ErrorCode::InvalidParams,
-				};
-
-				error_code.into()
-			})
+		self.backend
+			.find_by_bank_account_id(&bank_account.id, &filter)
+			.await
+			.map_err(|err| oracle_error_for_card(err, &card_number).into())
 	}
 
 	async fn get_bank_account(&self, card_number: String) -> RpcResult<BankAccount> {
 		log::debug!("Received get_bank_account request: {:?}", card_number);
 
 		let ba = self
-			.processor
-			.bank_account_controller
+			.backend
 			.find_by_card_number(&card_number)
 			.await
-			.map_err(|e| {
-				log::debug!("Error: {:?}", e);
-				ErrorCode::InvalidParams
+			.map_err(|err| {
+				log::debug!("Error: {:?}", err);
+				oracle_error_for_card(err, &card_number)
 			})?;
 
-		ba.ok_or(ErrorCode::InvalidParams.into())
+		ba.ok_or_else(|| {
+			OracleError::CardNotFound { card_number_hash: hash_card_number(&card_number) }.into()
+		})
 	}
 
 	async fn get_batch_balances(
@@ -124,9 +562,9 @@ impl OracleApiServer<IsoMsg> for OracleApiImpl {
 		signature: Vec<u8>,
 		account_ids: Vec<String>,
 	) -> RpcResult<Vec<(String, u32)>> {
-		let signature = signature.try_into().map_err(|_| ErrorCode::InvalidParams)?;
+		let signature = signature.try_into().map_err(|_| OracleError::InvalidSignature)?;
 
-		// message is JSON serialized array of account ids, so we need 
+		// message is JSON serialized array of account ids, so we need
 		// to include the brackets and quotes in the message
 		let message = {
 			let mut message = Vec::new();
@@ -144,21 +582,20 @@ impl OracleApiServer<IsoMsg> for OracleApiImpl {
 
 		if !sr25519::verify(&Signature(signature), &message[..], &self.signer) {
 			log::error!("Invalid signature");
-			return Err(ErrorCode::InvalidParams.into());
+			return Err(OracleError::InvalidSignature.into());
 		}
 
 		let mut balances = Vec::new();
 
 		for account_id in account_ids {
 			let ba = self
-				.processor
-				.bank_account_controller
+				.backend
 				.find_by_account_id(&account_id)
 				.await
-				.map_err(|e| {
-				log::error!("Error: {:?}", e);
-				ErrorCode::InvalidParams
-			})?;
+				.map_err(|err| {
+					log::error!("Error: {:?}", err);
+					oracle_error_for_card(err, &account_id)
+				})?;
 
 			if let Some(ba) = ba {
 				balances.push((account_id, ba.balance));
@@ -167,6 +604,75 @@ impl OracleApiServer<IsoMsg> for OracleApiImpl {
 
 		Ok(balances)
 	}
+
+	async fn subscribe_transaction(
+		&self,
+		pending: PendingSubscriptionSink,
+		card_number_or_account_id: String,
+	) -> SubscriptionResult {
+		let bank_account = match self.backend.find_by_card_number(&card_number_or_account_id).await {
+			Ok(Some(ba)) => Some(ba),
+			_ => self.backend.find_by_account_id(&card_number_or_account_id).await.unwrap_or(None),
+		};
+
+		let Some(bank_account) = bank_account else {
+			let card_number_hash = hash_card_number(&card_number_or_account_id);
+			pending.reject(ErrorObjectOwned::from(OracleError::CardNotFound { card_number_hash })).await;
+			return Ok(());
+		};
+
+		forward_transaction_subscription(
+			pending,
+			bank_account.id,
+			card_number_or_account_id,
+			self.transaction_sender.subscribe(),
+		)
+		.await
+	}
+}
+
+/// Accepts `pending`, then forwards every transaction matching `bank_account_id` from
+/// `receiver` to the sink until the client unsubscribes, the connection drops, or the
+/// subscriber lags behind the broadcast channel.
+///
+/// Shared by every [`OracleApiImpl`] instantiation so they only differ in how they resolve
+/// `bank_account_id` and publish transactions, not in how they stream them.
+async fn forward_transaction_subscription(
+	pending: PendingSubscriptionSink,
+	bank_account_id: uuid::Uuid,
+	subscriber_key: String,
+	mut receiver: broadcast::Receiver<Transaction>,
+) -> SubscriptionResult {
+	let sink = pending.accept().await?;
+
+	tokio::spawn(async move {
+		loop {
+			tokio::select! {
+				_ = sink.closed() => break,
+				received = receiver.recv() => {
+					match received {
+						Ok(transaction) if transaction.bank_account_id == bank_account_id => {
+							let Ok(message) = SubscriptionMessage::from_json(&transaction) else { break };
+							if sink.send(message).await.is_err() {
+								break;
+							}
+						},
+						Ok(_) => continue,
+						Err(broadcast::error::RecvError::Lagged(skipped)) => {
+							log::warn!(
+								"Transaction subscriber for {} lagged behind by {} messages, closing",
+								subscriber_key, skipped
+							);
+							break;
+						},
+						Err(broadcast::error::RecvError::Closed) => break,
+					}
+				}
+			}
+		}
+	});
+
+	Ok(())
 }
 
 /// Run ISO8583 Message Processor
@@ -226,7 +732,8 @@ async fn run_server(
 	let server = Server::builder().build(format!("0.0.0.0:{}", rpc_port)).await?;
 
 	let addr = server.local_addr()?;
-	let oracle_impl = OracleApiImpl { processor, signer: ocw_signer };
+	let (transaction_sender, _) = broadcast::channel(TRANSACTION_BROADCAST_CAPACITY);
+	let oracle_impl = OracleApiImpl { backend: processor, signer: ocw_signer, transaction_sender };
 
 	let server_handle = server.start(oracle_impl.into_rpc());
 
@@ -236,3 +743,429 @@ async fn run_server(
 
 	Ok(addr)
 }
+
+/// In-memory, `DEV_ACCOUNTS`-seeded [`BankAccountRepository`]/[`TransactionRepository`]/
+/// [`Iso8583Submitter`], used by [`run_in_memory`] so tests (and downstream users embedding the
+/// gateway) can exercise the Oracle RPC API without a Postgres-backed `Iso8583MessageProcessor`.
+#[derive(Default)]
+struct InMemoryStore {
+	bank_accounts: RwLock<HashMap<uuid::Uuid, BankAccount>>,
+	transactions: RwLock<HashMap<uuid::Uuid, Vec<Transaction>>>,
+}
+
+impl InMemoryStore {
+	/// Builds a store seeded with the same fixture accounts `run`'s `dev_mode` inserts
+	async fn seeded() -> Self {
+		let store = Self::default();
+
+		for account in DEV_ACCOUNTS.iter() {
+			let expiration_date = if account.0 != "Eve" {
+				Utc::now().checked_add_months(Months::new(48)).expect("valid date")
+			} else {
+				Utc::now().checked_sub_months(Months::new(2)).expect("safe; qed")
+			};
+
+			let id = uuid::Uuid::new_v4();
+			let bank_account = BankAccount {
+				id,
+				card_number: account.1.to_string(),
+				card_holder_first_name: account.0.to_string(),
+				card_holder_last_name: account.0.to_string(),
+				card_cvv: account.2.to_string(),
+				card_expiration_date: expiration_date,
+				balance: account.3,
+				nonce: 0,
+				account_id: Some(account.4.trim_start_matches("0x").to_string()),
+			};
+
+			store.bank_accounts.write().await.insert(id, bank_account);
+		}
+
+		store
+	}
+
+	/// Validates `iso_msg` and builds the `Transaction` it would produce, without recording it —
+	/// split out of `submit` so `submit_batch`'s atomic path can validate every message in a
+	/// batch before committing any of them.
+	async fn build_transaction(&self, iso_msg: &mut Vec<u8>) -> Result<(Vec<u8>, Transaction), DomainError> {
+		let card_number = String::from_utf8_lossy(iso_msg).into_owned();
+
+		let bank_account = self
+			.find_by_card_number(&card_number)
+			.await?
+			.ok_or_else(|| DomainError::NotFound(format!("no bank account for card {}", card_number)))?;
+
+		let transaction = Transaction {
+			id: uuid::Uuid::new_v4(),
+			bank_account_id: bank_account.id,
+			status: TxStatus::Processed,
+			created_at: Utc::now(),
+		};
+
+		Ok((iso_msg.clone(), transaction))
+	}
+}
+
+#[async_trait]
+impl BankAccountRepository for InMemoryStore {
+	async fn find_by_card_number(&self, card_number: &str) -> Result<Option<BankAccount>, DomainError> {
+		Ok(self.bank_accounts.read().await.values().find(|ba| ba.card_number == card_number).cloned())
+	}
+
+	async fn find_by_account_id(&self, account_id: &str) -> Result<Option<BankAccount>, DomainError> {
+		Ok(self
+			.bank_accounts
+			.read()
+			.await
+			.values()
+			.find(|ba| ba.account_id.as_deref() == Some(account_id))
+			.cloned())
+	}
+}
+
+#[async_trait]
+impl TransactionRepository for InMemoryStore {
+	async fn find_by_bank_account_id(
+		&self,
+		bank_account_id: &uuid::Uuid,
+		filter: &GetTransactionsFilter,
+	) -> Result<Vec<Transaction>, DomainError> {
+		let transactions = self.transactions.read().await.get(bank_account_id).cloned().unwrap_or_default();
+		Ok(apply_transactions_filter(transactions, filter))
+	}
+
+	async fn insert(&self, transaction: Transaction) -> Result<(), DomainError> {
+		self.transactions.write().await.entry(transaction.bank_account_id).or_default().push(transaction);
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl Iso8583Submitter for InMemoryStore {
+	/// The in-memory fixture has no ISO8583 parser, so `iso_msg` is interpreted directly as the
+	/// UTF-8 card number to settle against. The response echoes the submitted bytes back, and a
+	/// real transaction is recorded so `get_transactions`/`subscribe_transaction` have something
+	/// to observe.
+	async fn submit(&self, iso_msg: &mut Vec<u8>) -> Result<(Vec<u8>, Transaction), DomainError> {
+		let (response, transaction) = self.build_transaction(iso_msg).await?;
+		self.insert(transaction.clone()).await?;
+		Ok((response, transaction))
+	}
+
+	async fn submit_batch(
+		&self,
+		msgs: Vec<Vec<u8>>,
+		atomic: bool,
+	) -> Vec<Result<(Vec<u8>, Transaction), DomainError>> {
+		if !atomic {
+			let mut results = Vec::with_capacity(msgs.len());
+			for mut msg in msgs {
+				results.push(self.submit(&mut msg).await);
+			}
+			return results;
+		}
+
+		// Atomic batch: validate and build every transaction before recording any of them, so a
+		// failure partway through the batch leaves nothing committed — mirroring a single
+		// rolled-back database transaction around the whole batch.
+		let mut built = Vec::with_capacity(msgs.len());
+		for mut msg in msgs {
+			built.push(self.build_transaction(&mut msg).await);
+		}
+
+		if built.iter().any(Result::is_err) {
+			return built
+				.into_iter()
+				.map(|result| match result {
+					Ok(_) => Err(DomainError::BadRequest(
+						"batch rolled back because another message in it failed".to_string(),
+					)),
+					Err(err) => Err(err),
+				})
+				.collect();
+		}
+
+		for result in &built {
+			if let Ok((_, transaction)) = result {
+				let _ = self.insert(transaction.clone()).await;
+			}
+		}
+
+		built
+	}
+
+	async fn wait_for_finality(&self, _transaction: &Transaction) {
+		// The fixture has no chain or OCW to observe; finality is treated as immediate.
+	}
+}
+
+/// Starts an in-process Oracle RPC server over `backend` and returns a connected client.
+///
+/// Generic so tests can swap in a stand-in `backend` (e.g. one whose `wait_for_finality` is
+/// under the test's control) without duplicating the bootstrap below; [`run_in_memory`] is the
+/// public, `InMemoryStore`-backed instantiation.
+async fn run_with_backend<P>(backend: P) -> anyhow::Result<(SocketAddr, impl OracleApiClient<IsoMsg>)>
+where
+	P: BankAccountRepository + TransactionRepository + Iso8583Submitter + 'static,
+{
+	let store = Arc::new(backend);
+	let (transaction_sender, _) = broadcast::channel(TRANSACTION_BROADCAST_CAPACITY);
+	// There is no real OCW for the fixture to authenticate against; a fixed dev keypair is
+	// enough to exercise `get_batch_balances`'s signature check deterministically.
+	let signer = sr25519::dev::alice();
+	let oracle_impl = OracleApiImpl { backend: store, signer: signer.public_key(), transaction_sender };
+
+	let server = Server::builder().build("127.0.0.1:0").await?;
+	let addr = server.local_addr()?;
+	let server_handle = server.start(oracle_impl.into_rpc());
+	tokio::spawn(server_handle.stopped());
+
+	let client = WsClientBuilder::default().build(format!("ws://{}", addr)).await?;
+
+	Ok((addr, client))
+}
+
+/// Runs the Oracle RPC API over in-memory data, seeded from `DEV_ACCOUNTS`, with no
+/// Postgres-backed `Iso8583MessageProcessor`. The server is bound on an ephemeral loopback
+/// port (`127.0.0.1:0`) and the returned client is a WebSocket client connected to it.
+///
+/// Offers the accounts `run`'s `dev_mode` flag inserts as a reusable fixture: tests (and
+/// downstream users embedding the gateway) can spin one up, drive it through the generated
+/// `OracleApiClient`, and assert on ISO8583 round-trips deterministically.
+pub async fn run_in_memory() -> anyhow::Result<(SocketAddr, impl OracleApiClient<IsoMsg>)> {
+	run_with_backend(InMemoryStore::seeded().await).await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// A submitted ISO8583 message should be both retrievable via `get_transactions` and pushed
+	/// to an open `subscribe_transaction` subscription for the same card.
+	#[tokio::test]
+	async fn submit_iso8583_round_trips_through_in_memory_fixture() {
+		let (_, client) = run_in_memory().await.expect("in-memory server starts");
+		let card_number = DEV_ACCOUNTS[0].1.to_string();
+
+		let mut subscription = client
+			.subscribe_transaction(card_number.clone())
+			.await
+			.expect("subscription is accepted");
+
+		let response = client
+			.submit_iso8583(card_number.clone().into_bytes(), None)
+			.await
+			.expect("submission succeeds");
+		assert_eq!(response, card_number.clone().into_bytes());
+
+		let pushed: Transaction = tokio::time::timeout(Duration::from_secs(1), subscription.next())
+			.await
+			.expect("subscription fires before timeout")
+			.expect("subscription stream is still open")
+			.expect("subscription message decodes");
+
+		let transactions = client
+			.get_transactions(card_number, None)
+			.await
+			.expect("get_transactions succeeds");
+
+		assert_eq!(transactions.len(), 1);
+		assert_eq!(transactions[0].id, pushed.id);
+	}
+
+	/// An atomic batch with one message for a card that doesn't exist must leave no trace of the
+	/// other, otherwise-valid messages in the batch: every result comes back `Err`, and none of
+	/// the valid cards gain a recorded transaction.
+	#[tokio::test]
+	async fn submit_iso8583_batch_atomic_rolls_back_every_message_on_one_failure() {
+		let (_, client) = run_in_memory().await.expect("in-memory server starts");
+		let valid_card = DEV_ACCOUNTS[0].1.to_string();
+		let other_valid_card = DEV_ACCOUNTS[1].1.to_string();
+
+		let results = client
+			.submit_iso8583_batch(
+				vec![valid_card.clone().into_bytes(), b"not-a-real-card".to_vec(), other_valid_card.clone().into_bytes()],
+				true,
+			)
+			.await
+			.expect("batch call itself succeeds");
+
+		assert!(results.iter().all(Result::is_err), "every entry should fail when atomic batch rolls back");
+
+		let valid_card_transactions =
+			client.get_transactions(valid_card, None).await.expect("get_transactions succeeds");
+		let other_valid_card_transactions =
+			client.get_transactions(other_valid_card, None).await.expect("get_transactions succeeds");
+
+		assert!(valid_card_transactions.is_empty(), "rolled-back batch must not record a transaction");
+		assert!(other_valid_card_transactions.is_empty(), "rolled-back batch must not record a transaction");
+	}
+
+	/// Wraps [`InMemoryStore`] but never resolves `wait_for_finality`, so
+	/// `Commitment::Finalized`'s timeout path can be exercised deterministically instead of
+	/// relying on a live chain connection.
+	struct NeverFinalizingStore(InMemoryStore);
+
+	#[async_trait]
+	impl BankAccountRepository for NeverFinalizingStore {
+		async fn find_by_card_number(&self, card_number: &str) -> Result<Option<BankAccount>, DomainError> {
+			self.0.find_by_card_number(card_number).await
+		}
+
+		async fn find_by_account_id(&self, account_id: &str) -> Result<Option<BankAccount>, DomainError> {
+			self.0.find_by_account_id(account_id).await
+		}
+	}
+
+	#[async_trait]
+	impl TransactionRepository for NeverFinalizingStore {
+		async fn find_by_bank_account_id(
+			&self,
+			bank_account_id: &uuid::Uuid,
+			filter: &GetTransactionsFilter,
+		) -> Result<Vec<Transaction>, DomainError> {
+			self.0.find_by_bank_account_id(bank_account_id, filter).await
+		}
+
+		async fn insert(&self, transaction: Transaction) -> Result<(), DomainError> {
+			self.0.insert(transaction).await
+		}
+	}
+
+	#[async_trait]
+	impl Iso8583Submitter for NeverFinalizingStore {
+		async fn submit(&self, iso_msg: &mut Vec<u8>) -> Result<(Vec<u8>, Transaction), DomainError> {
+			self.0.submit(iso_msg).await
+		}
+
+		async fn submit_batch(
+			&self,
+			msgs: Vec<Vec<u8>>,
+			atomic: bool,
+		) -> Vec<Result<(Vec<u8>, Transaction), DomainError>> {
+			self.0.submit_batch(msgs, atomic).await
+		}
+
+		async fn wait_for_finality(&self, _transaction: &Transaction) {
+			std::future::pending().await
+		}
+	}
+
+	/// `Commitment::Finalized` should return normally once `wait_for_finality` resolves.
+	#[tokio::test]
+	async fn submit_iso8583_returns_once_finality_is_reached() {
+		let (_, client) = run_in_memory().await.expect("in-memory server starts");
+		let card_number = DEV_ACCOUNTS[0].1.to_string();
+		let config = SubmitConfig { commitment: Commitment::Finalized, timeout_ms: 1_000 };
+
+		let response = client
+			.submit_iso8583(card_number.clone().into_bytes(), Some(config))
+			.await
+			.expect("submission reaches finality before the timeout");
+		assert_eq!(response, card_number.into_bytes());
+	}
+
+	/// `Commitment::Finalized` must surface `OracleError::FinalityTimeout` rather than hang
+	/// forever when the backend's `wait_for_finality` never resolves.
+	#[tokio::test]
+	async fn submit_iso8583_times_out_waiting_for_finality_that_never_arrives() {
+		let (_, client) =
+			run_with_backend(NeverFinalizingStore(InMemoryStore::seeded().await)).await.expect("server starts");
+		let card_number = DEV_ACCOUNTS[0].1.to_string();
+		let config = SubmitConfig { commitment: Commitment::Finalized, timeout_ms: 50 };
+
+		let err = client
+			.submit_iso8583(card_number.into_bytes(), Some(config))
+			.await
+			.expect_err("never-finalizing backend must time out rather than hang");
+		assert!(err.to_string().contains("timed out"), "unexpected error: {}", err);
+	}
+
+	/// Builds `count` transactions for `bank_account_id`, newest first (`created_at` descending),
+	/// spaced a second apart so ordering and cursor lookups are deterministic.
+	fn transactions_newest_first(bank_account_id: uuid::Uuid, count: u32) -> Vec<Transaction> {
+		let now = Utc::now();
+		(0..count)
+			.map(|i| Transaction {
+				id: uuid::Uuid::new_v4(),
+				bank_account_id,
+				status: TxStatus::Processed,
+				created_at: now - chrono::Duration::seconds(i as i64),
+			})
+			.collect()
+	}
+
+	#[test]
+	fn apply_transactions_filter_orders_most_recent_first() {
+		let bank_account_id = uuid::Uuid::new_v4();
+		let transactions = transactions_newest_first(bank_account_id, 3);
+
+		// Feed them in reverse (oldest first) to confirm the function itself re-sorts them.
+		let mut shuffled = transactions.clone();
+		shuffled.reverse();
+
+		let filtered = apply_transactions_filter(shuffled, &GetTransactionsFilter::default());
+		assert_eq!(filtered.iter().map(|tx| tx.id).collect::<Vec<_>>(), transactions.iter().map(|tx| tx.id).collect::<Vec<_>>());
+	}
+
+	#[test]
+	fn apply_transactions_filter_applies_before_and_until_cursors() {
+		let bank_account_id = uuid::Uuid::new_v4();
+		let transactions = transactions_newest_first(bank_account_id, 5);
+
+		let filter =
+			GetTransactionsFilter { before: Some(transactions[1].id), until: Some(transactions[3].id), ..Default::default() };
+		let filtered = apply_transactions_filter(transactions.clone(), &filter);
+
+		assert_eq!(filtered.len(), 1);
+		assert_eq!(filtered[0].id, transactions[2].id);
+	}
+
+	/// `until` only truncates when its cursor is actually present in the page being filtered; a
+	/// cursor from a different account, or one already paged past, is a no-op rather than an
+	/// error — matching the Solana `getConfirmedSignaturesForAddress2` semantics this type's doc
+	/// comment already calls out.
+	#[test]
+	fn apply_transactions_filter_ignores_an_until_cursor_not_present_in_the_result_set() {
+		let bank_account_id = uuid::Uuid::new_v4();
+		let transactions = transactions_newest_first(bank_account_id, 3);
+
+		let filter = GetTransactionsFilter { until: Some(uuid::Uuid::new_v4()), ..Default::default() };
+		let filtered = apply_transactions_filter(transactions.clone(), &filter);
+
+		assert_eq!(filtered.len(), transactions.len());
+	}
+
+	#[test]
+	fn apply_transactions_filter_filters_by_status() {
+		let bank_account_id = uuid::Uuid::new_v4();
+		let transactions = transactions_newest_first(bank_account_id, 3);
+
+		let filter = GetTransactionsFilter { status: Some(TxStatus::Processed), ..Default::default() };
+		let filtered = apply_transactions_filter(transactions.clone(), &filter);
+
+		// `op_core::transaction::models::TxStatus` has no second variant in this tree to build a
+		// non-matching fixture from, so this only confirms a matching status is kept, not that a
+		// mismatched one is dropped.
+		assert_eq!(filtered.len(), transactions.len());
+	}
+
+	#[test]
+	fn apply_transactions_filter_defaults_limit_when_unspecified() {
+		let bank_account_id = uuid::Uuid::new_v4();
+		let transactions = transactions_newest_first(bank_account_id, DEFAULT_GET_TRANSACTIONS_LIMIT + 10);
+
+		let filtered = apply_transactions_filter(transactions, &GetTransactionsFilter::default());
+		assert_eq!(filtered.len(), DEFAULT_GET_TRANSACTIONS_LIMIT as usize);
+	}
+
+	#[test]
+	fn apply_transactions_filter_caps_limit_at_the_server_maximum() {
+		let bank_account_id = uuid::Uuid::new_v4();
+		let transactions = transactions_newest_first(bank_account_id, MAX_GET_TRANSACTIONS_LIMIT + 10);
+
+		let filter = GetTransactionsFilter { limit: Some(MAX_GET_TRANSACTIONS_LIMIT + 50), ..Default::default() };
+		let filtered = apply_transactions_filter(transactions, &filter);
+		assert_eq!(filtered.len(), MAX_GET_TRANSACTIONS_LIMIT as usize);
+	}
+}